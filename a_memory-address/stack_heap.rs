@@ -1,9 +1,218 @@
+/// The broad memory segments a pointer can fall into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Stack,
+    Heap,
+    Static,
+    Text,
+    Unknown,
+}
+
+/// Reference addresses captured once at startup, one per segment, used
+/// as anchors to classify arbitrary addresses by proximity.
+struct Anchors {
+    stack: usize,
+    heap: usize,
+    static_mem: usize,
+    text: usize,
+}
+
+/// Classify `addr` as belonging to the stack, heap, static, or text
+/// segment by picking whichever anchor it's closest to.
+///
+/// Stack and heap addresses aren't fixed offsets from each other (the
+/// two regions grow toward one another), so rather than compare against
+/// hardcoded constants we measure distance to the captured anchors and
+/// take the nearest one.
+fn classify_address(addr: usize, anchors: &Anchors) -> Region {
+    let distances = [
+        (Region::Stack, addr.abs_diff(anchors.stack)),
+        (Region::Heap, addr.abs_diff(anchors.heap)),
+        (Region::Static, addr.abs_diff(anchors.static_mem)),
+        (Region::Text, addr.abs_diff(anchors.text)),
+    ];
+
+    distances
+        .into_iter()
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(region, _)| region)
+        .unwrap_or(Region::Unknown)
+}
+
+/// Returns a raw pointer to a local variable whose stack slot is freed
+/// the moment this function returns. Reading through the returned
+/// pointer afterwards is unsound — the address is still the same, but
+/// nothing says what's backing it anymore.
+#[allow(dangling_pointers_from_locals)]
+fn leaked_local() -> *const i32 {
+    let local = 99;
+    &local as *const i32
+}
+
+/// A throwaway function call made purely to push a new frame over the
+/// stack slot `leaked_local` just vacated, so the reuse is observable.
+fn clobber_stack() {
+    let filler = [7_i32; 4];
+    std::hint::black_box(&filler);
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+/// Low-level entry point: dump `len` bytes starting at `start` as a
+/// hex + ASCII listing, eight bytes per row.
+///
+/// # Safety
+/// `start` must point to at least `len` readable bytes.
+unsafe fn scan_bytes(start: *const u8, len: usize) {
+    let bytes = unsafe { std::slice::from_raw_parts(start, len) };
+    for row in bytes.chunks(8) {
+        let hex: Vec<String> = row.iter().map(|b| format!("{:02x}", b)).collect();
+        let ascii: String = row
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+            .collect();
+        println!("  {:<24} {}", hex.join(" "), ascii);
+    }
+}
+
+/// Safe wrapper around `scan_bytes` for any `&T`: the byte span is
+/// computed via `size_of_val`, so callers never have to get `len` right
+/// by hand.
+fn scan_value<T>(value: &T) {
+    let len = std::mem::size_of_val(value);
+    let start = value as *const T as *const u8;
+    // SAFETY: `start` points at `value`, which is guaranteed to have
+    // `len` readable bytes behind it for as long as `value` is borrowed.
+    unsafe { scan_bytes(start, len) };
+}
+
+/// A mutable global, in contrast to the compile-time-initialized
+/// immutable `GLOBAL_VAR`. All `unsafe` needed to touch it is confined
+/// to the accessors below.
+static mut COUNTER: i32 = 0;
+
+/// Safe accessor: read the current value of the mutable global.
+fn global_get() -> i32 {
+    // SAFETY: the program is single-threaded, and these accessors are the
+    // only places `COUNTER` is ever touched directly.
+    unsafe { COUNTER }
+}
+
+/// Safe accessor: increment the mutable global by one.
+fn global_incr() {
+    // SAFETY: see `global_get`.
+    unsafe {
+        COUNTER += 1;
+    }
+}
+
+/// Safe accessor: the address of the mutable global, for comparison
+/// against `GLOBAL_VAR`'s address.
+fn global_addr() -> usize {
+    std::ptr::addr_of!(COUNTER) as usize
+}
+
 fn main() {
     let stack_var = 10;              // stack variable
     let heap_var = Box::new(20);     // heap allocation using Box
     static GLOBAL_VAR: i32 = 42;     // global/static memory
 
-    println!("Address of stack_var: {:p}", &stack_var);
-    println!("Address of heap_var: {:p}", &*heap_var); // deref Box
-    println!("Address of GLOBAL_VAR: {:p}", &GLOBAL_VAR);
-}
\ No newline at end of file
+    let anchors = Anchors {
+        stack: &stack_var as *const _ as usize,
+        heap: &*heap_var as *const _ as usize,
+        static_mem: &GLOBAL_VAR as *const _ as usize,
+        text: main as *const () as usize,
+    };
+
+    println!(
+        "Address of stack_var: {:p} ({:?})",
+        &stack_var,
+        classify_address(anchors.stack, &anchors)
+    );
+    println!(
+        "Address of heap_var: {:p} ({:?})", // deref Box
+        &*heap_var,
+        classify_address(anchors.heap, &anchors)
+    );
+    println!(
+        "Address of GLOBAL_VAR: {:p} ({:?})",
+        &GLOBAL_VAR,
+        classify_address(anchors.static_mem, &anchors)
+    );
+
+    println!("Size of stack_var: {} bytes", std::mem::size_of_val(&stack_var));
+    println!(
+        "Size of heap_var (the Box pointer on the stack): {} bytes",
+        std::mem::size_of_val(&heap_var)
+    );
+    println!(
+        "Size of *heap_var (the i32 on the heap): {} bytes",
+        std::mem::size_of_val(&*heap_var)
+    );
+
+    let stack_point = Point { x: 1.0, y: 2.0 };
+    let boxed_point = Box::new(Point { x: 3.0, y: 4.0 });
+    println!(
+        "Size of stack_point: {} bytes",
+        std::mem::size_of_val(&stack_point)
+    );
+    println!(
+        "Size of boxed_point (the Box pointer on the stack): {} bytes",
+        std::mem::size_of_val(&boxed_point)
+    );
+    println!(
+        "Size of *boxed_point (the Point on the heap): {} bytes",
+        std::mem::size_of_val(&*boxed_point)
+    );
+
+    let moved_point = *boxed_point; // deref moves the Point back onto the stack
+    println!(
+        "Size of moved_point (pulled back onto the stack): {} bytes ({:?})",
+        std::mem::size_of_val(&moved_point),
+        moved_point
+    );
+
+    println!(
+        "Address of COUNTER (mutable global): {:#x}, starting value {}",
+        global_addr(),
+        global_get()
+    );
+    for _ in 0..3 {
+        global_incr();
+    }
+    println!(
+        "Address of COUNTER after mutation: {:#x} (unchanged), value now {}",
+        global_addr(),
+        global_get()
+    );
+
+    println!("Bytes backing heap_var:");
+    scan_value(&*heap_var);
+    println!("Bytes backing GLOBAL_VAR:");
+    scan_value(&GLOBAL_VAR);
+
+    let dangling = leaked_local();
+    println!(
+        "Address of leaked_local's local: {:p} ({:?})",
+        dangling,
+        classify_address(dangling as usize, &anchors)
+    );
+
+    clobber_stack();
+
+    // SAFETY: this is intentionally unsound. `dangling` points at a stack
+    // slot that was freed when `leaked_local` returned; `clobber_stack`
+    // has since reused that same slot for its own locals. Reading through
+    // `dangling` here is undefined behavior — we only do it to show the
+    // original value is gone, unlike GLOBAL_VAR, whose address never moves.
+    let stale_value = unsafe { *dangling };
+    println!(
+        "Value read back through the dangling pointer: {} (stack slot reused; GLOBAL_VAR stays put at {:p})",
+        stale_value, &GLOBAL_VAR
+    );
+}